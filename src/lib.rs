@@ -13,9 +13,64 @@
 //! Contains matrix definitions and the generic implementation
 pub mod matrix_operations {
     //! Generic implementation for Mul, Add, and Sub from core::ops
+    use core::fmt;
     use core::ops::Add;
+    use core::ops::Div;
+    use core::ops::Index;
+    use core::ops::IndexMut;
     use core::ops::Mul;
     use core::ops::Sub;
+    #[cfg(feature = "serde")]
+    use serde::de::Error as DeserializeError;
+    #[cfg(feature = "serde")]
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Errors produced by the fallible constructors and arithmetic operations on [`Matrix`].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum MatrixError {
+        /// Not every row of the input data had the same length.
+        RaggedRows,
+        /// The input data had no rows, or an empty first row.
+        Empty,
+        /// The two matrices did not have the dimensions required for the operation.
+        DimensionMismatch {
+            /// The dimension the left-hand operand required.
+            expected: (u32, u32),
+            /// The dimension that was actually found.
+            found: (u32, u32),
+        },
+        /// The left-hand matrix's column count did not match the right-hand matrix's row count.
+        IncompatibleForMul {
+            /// Column count of the left-hand matrix.
+            left_columns: u32,
+            /// Row count of the right-hand matrix.
+            right_rows: u32,
+        },
+    }
+
+    impl fmt::Display for MatrixError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                MatrixError::RaggedRows => write!(f, "matrix rows must all have equal length"),
+                MatrixError::Empty => write!(f, "matrix data must have at least one row and column"),
+                MatrixError::DimensionMismatch { expected, found } => write!(
+                    f,
+                    "expected a matrix of dimension {:?}, found {:?}",
+                    expected, found
+                ),
+                MatrixError::IncompatibleForMul {
+                    left_columns,
+                    right_rows,
+                } => write!(
+                    f,
+                    "left-hand matrix has {} columns but right-hand matrix has {} rows",
+                    left_columns, right_rows
+                ),
+            }
+        }
+    }
+
+    impl std::error::Error for MatrixError {}
 
     /// Generic struct definition
     #[derive(Debug, Clone, PartialEq)]
@@ -26,9 +81,23 @@ pub mod matrix_operations {
         data: Vec<Vec<T>>,
     }
 
+    /// Result of an `LU` decomposition with partial pivoting, as produced by
+    /// [`Matrix::lu_decompose`].
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct LuDecomposition<T> {
+        /// Lower-triangular factor with a unit diagonal.
+        pub lower: Matrix<T>,
+        /// Upper-triangular factor.
+        pub upper: Matrix<T>,
+        /// `permutation[i]` is the index of the original row placed at row `i` of `lower`/`upper`.
+        pub permutation: Vec<usize>,
+        /// Sign of the row permutation (`1` or `-1`), used when recovering the determinant.
+        pub sign: i32,
+    }
+
     impl<T> Matrix<T>
     where
-        T: Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Default + Clone,
+        T: Clone,
     {
         /// Create an NxM matrix given a 2d vector with elements of type T
         pub fn from_data(data: Vec<Vec<T>>) -> Self {
@@ -43,6 +112,30 @@ pub mod matrix_operations {
             }
         }
 
+        /// Fallible version of [`Matrix::from_data`] that validates the input instead of
+        /// panicking.
+        ///
+        /// Returns [`MatrixError::Empty`] if `data` has no rows or an empty first row, and
+        /// [`MatrixError::RaggedRows`] if rows do not all have equal length.
+        pub fn try_from_data(data: Vec<Vec<T>>) -> Result<Self, MatrixError> {
+            let rows: u32 = data.len() as u32;
+            let columns: u32 = match data.first() {
+                Some(first_row) if !first_row.is_empty() => first_row.len() as u32,
+                _ => return Err(MatrixError::Empty),
+            };
+
+            if data.iter().any(|row| row.len() as u32 != columns) {
+                return Err(MatrixError::RaggedRows);
+            }
+
+            Ok(Matrix {
+                rows,
+                columns,
+                dimension: (rows, columns),
+                data,
+            })
+        }
+
         /// Create an NxM matrix where every entry is populated with the value passed in the constant param
         pub fn from_constant(dimension: (u32, u32), constant: T) -> Self {
             Matrix {
@@ -53,6 +146,35 @@ pub mod matrix_operations {
             }
         }
 
+        fn data_from_constant(r: u32, c: u32, v: T) -> Vec<Vec<T>> {
+            return vec![vec![v; c as usize]; r as usize];
+        }
+
+        /// Transpose matrix (i.e retrieve M^T for a matrix M)
+        pub fn transpose(self) -> Matrix<T> {
+            let mut data: Vec<Vec<T>> = Vec::with_capacity(self.columns as usize);
+
+            for j in 0..self.columns as usize {
+                let mut new_row: Vec<T> = Vec::with_capacity(self.rows as usize);
+                for i in 0..self.rows as usize {
+                    new_row.push(self.data[i][j].clone());
+                }
+                data.push(new_row);
+            }
+
+            Matrix {
+                rows: self.columns,
+                columns: self.rows,
+                dimension: (self.columns, self.rows),
+                data,
+            }
+        }
+    }
+
+    impl<T> Matrix<T>
+    where
+        T: Default + Clone,
+    {
         /// Create an NxM diagonal matrix with diagonal taking values from the supplied constant
         pub fn diagonal_from_constant(dimension: (u32, u32), constant: T) -> Self {
             let mut m: Matrix<T> = Matrix::default_from_dimension(dimension);
@@ -92,31 +214,308 @@ pub mod matrix_operations {
         fn data_from_zeroes(r: u32, c: u32) -> Vec<Vec<T>> {
             return vec![vec![T::default(); c as usize]; r as usize];
         }
+    }
 
-        fn data_from_constant(r: u32, c: u32, v: T) -> Vec<Vec<T>> {
-            return vec![vec![v; c as usize]; r as usize];
+    impl<T> Matrix<T> {
+        /// Number of rows.
+        pub fn rows(&self) -> u32 {
+            self.rows
         }
 
-        /// Transpose matrix (i.e retrieve M^T for a matrix M)
-        pub fn transpose(self) -> Matrix<T> {
-            let mut transposed_matrix: Matrix<T> =
-                Matrix::default_from_dimension((self.columns, self.rows));
+        /// Number of columns.
+        pub fn columns(&self) -> u32 {
+            self.columns
+        }
 
-            for i in 0..self.rows {
-                let iu: usize = i as usize;
-                for j in 0..self.columns {
-                    let ju: usize = j as usize;
-                    transposed_matrix.data[ju][iu] = self.data[iu][ju].clone();
+        /// `(rows, columns)`.
+        pub fn dimension(&self) -> (u32, u32) {
+            self.dimension
+        }
+
+        /// Borrow the entry at `(row, column)`, or `None` if either index is out of bounds.
+        pub fn get(&self, index: (usize, usize)) -> Option<&T> {
+            self.data.get(index.0)?.get(index.1)
+        }
+
+        /// Mutably borrow the entry at `(row, column)`, or `None` if either index is out of
+        /// bounds.
+        pub fn get_mut(&mut self, index: (usize, usize)) -> Option<&mut T> {
+            self.data.get_mut(index.0)?.get_mut(index.1)
+        }
+
+        /// Iterate over every entry in row-major order.
+        pub fn iter(&self) -> impl Iterator<Item = &T> {
+            self.data.iter().flat_map(|row| row.iter())
+        }
+
+        /// Iterate over each row as a borrowed slice.
+        pub fn row_iter(&self) -> impl Iterator<Item = &[T]> {
+            self.data.iter().map(Vec::as_slice)
+        }
+
+        /// Apply `f` to every entry, producing a matrix of the same shape with a (possibly
+        /// different) element type `U`.
+        pub fn map<U, F: Fn(&T) -> U>(&self, f: F) -> Matrix<U> {
+            let data: Vec<Vec<U>> = self
+                .data
+                .iter()
+                .map(|row| row.iter().map(&f).collect())
+                .collect();
+
+            Matrix {
+                rows: self.rows,
+                columns: self.columns,
+                dimension: self.dimension,
+                data,
+            }
+        }
+
+        /// Mutate every entry of `self` in place with `f`.
+        ///
+        /// Taking `self` by `&mut` (rather than returning a new matrix) avoids cloning every
+        /// entry for element types that are not `Copy`.
+        pub fn apply<F: FnMut(&mut T)>(&mut self, mut f: F) {
+            for row in self.data.iter_mut() {
+                for entry in row.iter_mut() {
+                    f(entry);
+                }
+            }
+        }
+
+        /// Fold `rhs` into `self` entrywise in place via `f(self_entry, rhs_entry)`.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `self` and `rhs` do not have the same dimension.
+        pub fn zip_apply<F: FnMut(&mut T, &T)>(&mut self, rhs: &Matrix<T>, mut f: F) {
+            if self.dimension != rhs.dimension {
+                panic!("Matrices must have the same dimension to zip_apply");
+            }
+
+            for (row, rhs_row) in self.data.iter_mut().zip(rhs.data.iter()) {
+                for (entry, rhs_entry) in row.iter_mut().zip(rhs_row.iter()) {
+                    f(entry, rhs_entry);
+                }
+            }
+        }
+    }
+
+    impl<T> Matrix<T>
+    where
+        T: Clone,
+    {
+        /// Iterate over each column, collected into an owned `Vec` since columns are not stored
+        /// contiguously.
+        pub fn column_iter(&self) -> impl Iterator<Item = Vec<T>> + '_ {
+            (0..self.columns as usize)
+                .map(move |j| self.data.iter().map(|row| row[j].clone()).collect())
+        }
+    }
+
+    impl<T> Index<(usize, usize)> for Matrix<T> {
+        type Output = T;
+
+        /// Index by `(row, column)`, panicking out of bounds just like `Vec`'s `Index`.
+        fn index(&self, index: (usize, usize)) -> &Self::Output {
+            &self.data[index.0][index.1]
+        }
+    }
+
+    impl<T> IndexMut<(usize, usize)> for Matrix<T> {
+        /// Index by `(row, column)`, panicking out of bounds just like `Vec`'s `IndexMut`.
+        fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+            &mut self.data[index.0][index.1]
+        }
+    }
+
+    impl<T> Matrix<T>
+    where
+        T: Mul<Output = T>
+            + Add<Output = T>
+            + Sub<Output = T>
+            + Div<Output = T>
+            + Default
+            + Clone
+            + PartialOrd,
+    {
+        /// Decompose a square matrix into a lower-triangular factor `L`, an upper-triangular
+        /// factor `U` and a row permutation, such that `P * self == L * U` for the permutation
+        /// `P` described by [`LuDecomposition::permutation`].
+        ///
+        /// Partial pivoting is used: at each step the largest-magnitude entry in the remaining
+        /// column is brought onto the diagonal before elimination, which keeps the method stable
+        /// for a wider range of inputs than naive Gaussian elimination.
+        ///
+        /// Returns `None` if `self` is not square or is singular (a zero pivot is encountered).
+        pub fn lu_decompose(&self) -> Option<LuDecomposition<T>> {
+            if self.rows != self.columns {
+                return None;
+            }
+
+            let n: usize = self.rows as usize;
+            let mut upper: Vec<Vec<T>> = self.data.clone();
+            let mut lower: Vec<Vec<T>> = Matrix::<T>::data_from_zeroes(self.rows, self.columns);
+            let mut permutation: Vec<usize> = (0..n).collect();
+            let mut sign: i32 = 1;
+
+            for k in 0..n {
+                let mut pivot_row: usize = k;
+                let mut pivot_magnitude: T = Self::magnitude(upper[k][k].clone());
+                for (i, row) in upper.iter().enumerate().skip(k + 1) {
+                    let candidate: T = Self::magnitude(row[k].clone());
+                    if candidate > pivot_magnitude {
+                        pivot_row = i;
+                        pivot_magnitude = candidate;
+                    }
+                }
+
+                if pivot_magnitude == T::default() {
+                    return None;
+                }
+
+                if pivot_row != k {
+                    upper.swap(k, pivot_row);
+                    lower.swap(k, pivot_row);
+                    permutation.swap(k, pivot_row);
+                    sign = -sign;
+                }
+
+                let pivot: T = upper[k][k].clone();
+                lower[k][k] = pivot.clone() / pivot.clone();
+
+                let (pivot_and_above, below) = upper.split_at_mut(k + 1);
+                let pivot_row_data: &Vec<T> = &pivot_and_above[k];
+                for (offset, row) in below.iter_mut().enumerate() {
+                    let i: usize = k + 1 + offset;
+                    let multiplier: T = row[k].clone() / pivot.clone();
+                    for (j, pivot_value) in pivot_row_data.iter().enumerate().skip(k) {
+                        row[j] = row[j].clone() - multiplier.clone() * pivot_value.clone();
+                    }
+                    lower[i][k] = multiplier;
                 }
             }
 
-            return transposed_matrix;
+            Some(LuDecomposition {
+                lower: Matrix {
+                    rows: self.rows,
+                    columns: self.columns,
+                    dimension: self.dimension,
+                    data: lower,
+                },
+                upper: Matrix {
+                    rows: self.rows,
+                    columns: self.columns,
+                    dimension: self.dimension,
+                    data: upper,
+                },
+                permutation,
+                sign,
+            })
+        }
+
+        /// Compute the determinant via [`Matrix::lu_decompose`].
+        ///
+        /// Returns `None` if the matrix is not square, is singular, or is 0x0 (there is no
+        /// pivot product to recover a determinant from, and `T` has no general notion of a
+        /// multiplicative unit to fall back on).
+        pub fn determinant(&self) -> Option<T> {
+            let lu: LuDecomposition<T> = self.lu_decompose()?;
+            let n: usize = self.rows as usize;
+
+            if n == 0 {
+                return None;
+            }
+
+            let mut product: T = lu.upper.data[0][0].clone();
+            for i in 1..n {
+                product = product * lu.upper.data[i][i].clone();
+            }
+
+            if lu.sign < 0 {
+                product = T::default() - product;
+            }
+
+            Some(product)
+        }
+
+        /// Compute the inverse via [`Matrix::lu_decompose`], solving `self * X = I` one column of
+        /// `X` at a time with forward and back substitution.
+        ///
+        /// Returns `None` if the matrix is not square or is singular.
+        pub fn inverse(&self) -> Option<Matrix<T>> {
+            let lu: LuDecomposition<T> = self.lu_decompose()?;
+            let n: usize = self.rows as usize;
+
+            if n == 0 {
+                return Some(Matrix::default_from_dimension((0, 0)));
+            }
+
+            let one: T = Self::unit(&lu.upper.data[0][0]);
+            let mut result: Vec<Vec<T>> = Matrix::<T>::data_from_zeroes(self.rows, self.columns);
+
+            // `col` selects which column of the identity is being solved for, not an index into
+            // any single container, so it doesn't fit the usual iterate-the-container pattern.
+            #[allow(clippy::needless_range_loop)]
+            for col in 0..n {
+                let mut y: Vec<T> = vec![T::default(); n];
+                for i in 0..n {
+                    let permuted_rhs: T = if lu.permutation[i] == col {
+                        one.clone()
+                    } else {
+                        T::default()
+                    };
+
+                    let mut sum: T = T::default();
+                    for (lower_value, y_value) in lu.lower.data[i][..i].iter().zip(y[..i].iter()) {
+                        sum = sum + lower_value.clone() * y_value.clone();
+                    }
+                    y[i] = (permuted_rhs - sum) / lu.lower.data[i][i].clone();
+                }
+
+                let mut x: Vec<T> = vec![T::default(); n];
+                for i in (0..n).rev() {
+                    let mut sum: T = T::default();
+                    for (upper_value, x_value) in
+                        lu.upper.data[i][(i + 1)..].iter().zip(x[(i + 1)..].iter())
+                    {
+                        sum = sum + upper_value.clone() * x_value.clone();
+                    }
+                    x[i] = (y[i].clone() - sum) / lu.upper.data[i][i].clone();
+                }
+
+                for (i, value) in x.into_iter().enumerate() {
+                    result[i][col] = value;
+                }
+            }
+
+            Some(Matrix {
+                rows: self.rows,
+                columns: self.columns,
+                dimension: self.dimension,
+                data: result,
+            })
+        }
+
+        /// Absolute value of `value`, expressed purely in terms of `Sub`, `Default` and
+        /// `PartialOrd` since `T` is not required to implement `Neg` or `Signed`.
+        fn magnitude(value: T) -> T {
+            if value < T::default() {
+                T::default() - value
+            } else {
+                value
+            }
+        }
+
+        /// The multiplicative identity for `T`, derived from a known-nonzero `reference` value
+        /// since `T` carries no `One` bound.
+        fn unit(reference: &T) -> T {
+            reference.clone() / reference.clone()
         }
     }
 
     impl<T> Add for Matrix<T>
     where
-        T: Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Default + Clone,
+        T: Add<Output = T> + Default + Clone,
     {
         type Output = Matrix<T>;
 
@@ -138,6 +537,9 @@ pub mod matrix_operations {
         }
     }
 
+    /// Side length at or above which [`Mul`] switches from the naive kernel to [`Matrix::strassen_mul`].
+    const STRASSEN_THRESHOLD: u32 = 64;
+
     impl<T> Mul for Matrix<T>
     where
         T: Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Default + Clone,
@@ -149,6 +551,23 @@ pub mod matrix_operations {
                 panic!("Incompatible dimensions for matrix multiplication.")
             }
 
+            if self.rows >= STRASSEN_THRESHOLD
+                || self.columns >= STRASSEN_THRESHOLD
+                || rhs.columns >= STRASSEN_THRESHOLD
+            {
+                self.strassen_mul(rhs)
+            } else {
+                Self::naive_mul(self, rhs)
+            }
+        }
+    }
+
+    impl<T> Matrix<T>
+    where
+        T: Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Default + Clone,
+    {
+        /// The naive O(n^3) triple-loop kernel used below [`STRASSEN_THRESHOLD`].
+        fn naive_mul(self, rhs: Self) -> Self {
             let mut resultant: Matrix<T> =
                 Matrix::<T>::default_from_rows_and_columns(self.rows, rhs.columns);
 
@@ -166,11 +585,135 @@ pub mod matrix_operations {
 
             return resultant;
         }
+
+        /// Multiply via Strassen's algorithm: pad both operands to the next power-of-two square
+        /// dimension, recurse until the padded size is at most [`STRASSEN_THRESHOLD`] and finish
+        /// each such block with the naive kernel, then crop the
+        /// result back down to `self.rows x rhs.columns`.
+        ///
+        /// Automatically used by [`Mul`] once any relevant dimension reaches
+        /// [`STRASSEN_THRESHOLD`]; exposed directly for callers who want the asymptotically
+        /// faster multiply regardless of size.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `self.columns != rhs.rows`.
+        pub fn strassen_mul(self, rhs: Self) -> Self {
+            if self.columns != rhs.rows {
+                panic!("Incompatible dimensions for matrix multiplication.")
+            }
+
+            let result_rows: u32 = self.rows;
+            let result_columns: u32 = rhs.columns;
+            let n: u32 = self
+                .rows
+                .max(self.columns)
+                .max(rhs.rows)
+                .max(rhs.columns)
+                .next_power_of_two();
+
+            let padded_a: Matrix<T> = self.pad_to(n, n);
+            let padded_b: Matrix<T> = rhs.pad_to(n, n);
+
+            Self::strassen_recursive(padded_a, padded_b).crop_to(result_rows, result_columns)
+        }
+
+        /// Recursive Strassen step over square, power-of-two-sized matrices.
+        ///
+        /// Bottoms out to [`Self::naive_mul`] well above a single element: the naive kernel has
+        /// better constants at small sizes, so recursing all the way down just adds overhead.
+        fn strassen_recursive(a: Self, b: Self) -> Self {
+            if a.rows <= STRASSEN_THRESHOLD {
+                return Self::naive_mul(a, b);
+            }
+
+            let (a11, a12, a21, a22) = a.quadrants();
+            let (b11, b12, b21, b22) = b.quadrants();
+
+            let m1 = Self::strassen_recursive(a11.clone() + a22.clone(), b11.clone() + b22.clone());
+            let m2 = Self::strassen_recursive(a21.clone() + a22.clone(), b11.clone());
+            let m3 = Self::strassen_recursive(a11.clone(), b12.clone() - b22.clone());
+            let m4 = Self::strassen_recursive(a22.clone(), b21.clone() - b11.clone());
+            let m5 = Self::strassen_recursive(a11.clone() + a12.clone(), b22.clone());
+            let m6 = Self::strassen_recursive(a21 - a11, b11 + b12);
+            let m7 = Self::strassen_recursive(a12 - a22, b21 + b22);
+
+            let c11 = m1.clone() + m4.clone() - m5.clone() + m7;
+            let c12 = m3.clone() + m5;
+            let c21 = m2.clone() + m4;
+            let c22 = m1 - m2 + m3 + m6;
+
+            Self::from_quadrants(c11, c12, c21, c22)
+        }
+
+        /// Split a square matrix of even dimension into its four equally-sized quadrants.
+        fn quadrants(&self) -> (Self, Self, Self, Self) {
+            let half: u32 = self.rows / 2;
+            let halfu: usize = half as usize;
+            let mut a11: Matrix<T> = Matrix::default_from_rows_and_columns(half, half);
+            let mut a12: Matrix<T> = Matrix::default_from_rows_and_columns(half, half);
+            let mut a21: Matrix<T> = Matrix::default_from_rows_and_columns(half, half);
+            let mut a22: Matrix<T> = Matrix::default_from_rows_and_columns(half, half);
+
+            for i in 0..halfu {
+                for j in 0..halfu {
+                    a11.data[i][j] = self.data[i][j].clone();
+                    a12.data[i][j] = self.data[i][j + halfu].clone();
+                    a21.data[i][j] = self.data[i + halfu][j].clone();
+                    a22.data[i][j] = self.data[i + halfu][j + halfu].clone();
+                }
+            }
+
+            (a11, a12, a21, a22)
+        }
+
+        /// Stitch four equally-sized quadrants back into a single square matrix.
+        fn from_quadrants(c11: Self, c12: Self, c21: Self, c22: Self) -> Self {
+            let half: u32 = c11.rows;
+            let halfu: usize = half as usize;
+            let mut combined: Matrix<T> =
+                Matrix::default_from_rows_and_columns(half * 2, half * 2);
+
+            for i in 0..halfu {
+                for j in 0..halfu {
+                    combined.data[i][j] = c11.data[i][j].clone();
+                    combined.data[i][j + halfu] = c12.data[i][j].clone();
+                    combined.data[i + halfu][j] = c21.data[i][j].clone();
+                    combined.data[i + halfu][j + halfu] = c22.data[i][j].clone();
+                }
+            }
+
+            combined
+        }
+
+        /// Copy `self` into the top-left corner of a zero-filled `rows x columns` matrix.
+        fn pad_to(&self, rows: u32, columns: u32) -> Self {
+            let mut padded: Matrix<T> = Matrix::default_from_rows_and_columns(rows, columns);
+            for i in 0..self.rows as usize {
+                for j in 0..self.columns as usize {
+                    padded.data[i][j] = self.data[i][j].clone();
+                }
+            }
+
+            padded
+        }
+
+        /// Copy the top-left `rows x columns` corner of `self` out into its own matrix.
+        fn crop_to(&self, rows: u32, columns: u32) -> Self {
+            let mut cropped: Matrix<T> = Matrix::default_from_rows_and_columns(rows, columns);
+            for i in 0..rows as usize {
+                for j in 0..columns as usize {
+                    cropped.data[i][j] = self.data[i][j].clone();
+                }
+            }
+
+            cropped
+        }
     }
 
     impl<T> Sub for Matrix<T>
     where
-        T: Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Default + Clone,
+        T: Sub<Output = T> + Default + Clone,
     {
         type Output = Matrix<T>;
 
@@ -192,9 +735,63 @@ pub mod matrix_operations {
         }
     }
 
-    impl<T> Default for Matrix<T>
+    impl<T> Matrix<T>
+    where
+        T: Add<Output = T> + Default + Clone,
+    {
+        /// Fallible version of [`Add`] that returns a [`MatrixError::DimensionMismatch`] instead
+        /// of panicking when the dimensions disagree.
+        pub fn checked_add(self, rhs: Self) -> Result<Self, MatrixError> {
+            if self.dimension != rhs.dimension {
+                return Err(MatrixError::DimensionMismatch {
+                    expected: self.dimension,
+                    found: rhs.dimension,
+                });
+            }
+
+            Ok(self + rhs)
+        }
+    }
+
+    impl<T> Matrix<T>
+    where
+        T: Sub<Output = T> + Default + Clone,
+    {
+        /// Fallible version of [`Sub`] that returns a [`MatrixError::DimensionMismatch`] instead
+        /// of panicking when the dimensions disagree.
+        pub fn checked_sub(self, rhs: Self) -> Result<Self, MatrixError> {
+            if self.dimension != rhs.dimension {
+                return Err(MatrixError::DimensionMismatch {
+                    expected: self.dimension,
+                    found: rhs.dimension,
+                });
+            }
+
+            Ok(self - rhs)
+        }
+    }
+
+    impl<T> Matrix<T>
     where
         T: Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Default + Clone,
+    {
+        /// Fallible version of [`Mul`] that returns a [`MatrixError::IncompatibleForMul`] instead
+        /// of panicking when the left-hand column count does not match the right-hand row count.
+        pub fn checked_mul(self, rhs: Self) -> Result<Self, MatrixError> {
+            if self.columns != rhs.rows {
+                return Err(MatrixError::IncompatibleForMul {
+                    left_columns: self.columns,
+                    right_rows: rhs.rows,
+                });
+            }
+
+            Ok(self * rhs)
+        }
+    }
+
+    impl<T> Default for Matrix<T>
+    where
+        T: Default + Clone,
     {
         fn default() -> Self {
             Self {
@@ -205,11 +802,59 @@ pub mod matrix_operations {
             }
         }
     }
+
+    #[cfg(feature = "serde")]
+    impl<T> Serialize for Matrix<T>
+    where
+        T: Serialize,
+    {
+        /// Serializes as the nested row-major data; `rows`/`columns`/`dimension` are redundant
+        /// and are recomputed on deserialization.
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            self.data.serialize(serializer)
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    impl<'de, T> Deserialize<'de> for Matrix<T>
+    where
+        T: Deserialize<'de> + Clone,
+    {
+        /// Deserializes the nested row-major data and re-validates that every row has equal
+        /// length, since a malformed payload could otherwise produce a ragged `Matrix`.
+        ///
+        /// Unlike [`Matrix::try_from_data`], a 0x0 or Nx0 payload is accepted rather than
+        /// rejected as [`MatrixError::Empty`]: those are legitimate matrices that
+        /// [`Matrix::serialize`] can itself produce, and deserialization must be their inverse.
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let data: Vec<Vec<T>> = Vec::deserialize(deserializer)?;
+            let rows: u32 = data.len() as u32;
+            let columns: u32 = data.first().map_or(0, |first_row| first_row.len() as u32);
+
+            if data.iter().any(|row| row.len() as u32 != columns) {
+                return Err(DeserializeError::custom(MatrixError::RaggedRows));
+            }
+
+            Ok(Matrix {
+                rows,
+                columns,
+                dimension: (rows, columns),
+                data,
+            })
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::matrix_operations::Matrix;
+    use super::matrix_operations::MatrixError;
 
     #[test]
     fn test_addition() {
@@ -246,4 +891,210 @@ mod tests {
 
         assert_eq!(mat_tens.clone() * unit_matrix, mat_tens);
     }
+
+    #[test]
+    fn test_determinant() {
+        let m: Matrix<f64> = Matrix::from_data(vec![
+            vec![1.0, 2.0, 3.0],
+            vec![4.0, 5.0, 6.0],
+            vec![7.0, 8.0, 10.0],
+        ]);
+
+        assert!((m.determinant().unwrap() - -3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_determinant_of_singular_matrix_is_none() {
+        let m: Matrix<f64> = Matrix::from_data(vec![
+            vec![1.0, 2.0, 3.0],
+            vec![2.0, 4.0, 6.0],
+            vec![7.0, 8.0, 10.0],
+        ]);
+
+        assert_eq!(m.determinant(), None);
+    }
+
+    #[test]
+    fn test_determinant_and_inverse_of_empty_matrix_do_not_panic() {
+        let m: Matrix<f64> = Matrix::default_from_dimension((0, 0));
+
+        assert_eq!(m.determinant(), None);
+        assert_eq!(m.inverse(), Some(Matrix::default_from_dimension((0, 0))));
+    }
+
+    #[test]
+    fn test_inverse() {
+        let m: Matrix<f64> = Matrix::from_data(vec![vec![2.0, 1.0], vec![1.0, 1.0]]);
+
+        let inverse: Matrix<f64> = m.clone().inverse().unwrap();
+        let expected_inverse: Matrix<f64> =
+            Matrix::from_data(vec![vec![1.0, -1.0], vec![-1.0, 2.0]]);
+
+        assert_eq!(inverse, expected_inverse);
+        assert_eq!(m * inverse, Matrix::diagonal_from_constant((2, 2), 1.0));
+    }
+
+    #[test]
+    fn test_map() {
+        let m: Matrix<i32> = Matrix::from_constant((2, 2), 3);
+        let doubled: Matrix<f64> = m.map(|v| *v as f64 * 2.0);
+
+        assert_eq!(doubled, Matrix::from_constant((2, 2), 6.0));
+    }
+
+    #[test]
+    fn test_apply() {
+        let mut m: Matrix<i32> = Matrix::from_constant((2, 2), 3);
+        m.apply(|v| *v *= 2);
+
+        assert_eq!(m, Matrix::from_constant((2, 2), 6));
+    }
+
+    #[test]
+    fn test_zip_apply() {
+        let mut m: Matrix<i32> = Matrix::from_constant((2, 2), 3);
+        let rhs: Matrix<i32> = Matrix::from_constant((2, 2), 4);
+        m.zip_apply(&rhs, |a, b| *a += *b);
+
+        assert_eq!(m, Matrix::from_constant((2, 2), 7));
+    }
+
+    #[test]
+    fn test_try_from_data_rejects_ragged_rows() {
+        let result = Matrix::try_from_data(vec![vec![1, 2], vec![3]]);
+
+        assert_eq!(result, Err(MatrixError::RaggedRows));
+    }
+
+    #[test]
+    fn test_try_from_data_rejects_empty() {
+        let result: Result<Matrix<i32>, MatrixError> = Matrix::try_from_data(vec![]);
+
+        assert_eq!(result, Err(MatrixError::Empty));
+    }
+
+    #[test]
+    fn test_checked_add_rejects_mismatched_dimensions() {
+        let a: Matrix<i32> = Matrix::from_constant((2, 2), 1);
+        let b: Matrix<i32> = Matrix::from_constant((3, 3), 1);
+
+        assert_eq!(
+            a.checked_add(b),
+            Err(MatrixError::DimensionMismatch {
+                expected: (2, 2),
+                found: (3, 3)
+            })
+        );
+    }
+
+    #[test]
+    fn test_checked_mul_rejects_incompatible_dimensions() {
+        let a: Matrix<i32> = Matrix::from_constant((2, 3), 1);
+        let b: Matrix<i32> = Matrix::from_constant((2, 2), 1);
+
+        assert_eq!(
+            a.checked_mul(b),
+            Err(MatrixError::IncompatibleForMul {
+                left_columns: 3,
+                right_rows: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_index_and_index_mut() {
+        let mut m: Matrix<i32> = Matrix::from_constant((2, 2), 0);
+        m[(0, 1)] = 5;
+
+        assert_eq!(m[(0, 1)], 5);
+        assert_eq!(m.get((1, 1)), Some(&0));
+        assert_eq!(m.get((2, 0)), None);
+    }
+
+    #[test]
+    fn test_iterators() {
+        let m: Matrix<i32> = Matrix::from_data(vec![vec![1, 2], vec![3, 4]]);
+
+        assert_eq!(m.iter().copied().collect::<Vec<i32>>(), vec![1, 2, 3, 4]);
+        assert_eq!(
+            m.row_iter().map(<[i32]>::to_vec).collect::<Vec<_>>(),
+            vec![vec![1, 2], vec![3, 4]]
+        );
+        assert_eq!(
+            m.column_iter().collect::<Vec<Vec<i32>>>(),
+            vec![vec![1, 3], vec![2, 4]]
+        );
+    }
+
+    #[test]
+    fn test_strassen_mul_matches_naive_mul() {
+        let a: Matrix<i32> = Matrix::from_data(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
+        let b: Matrix<i32> = Matrix::from_data(vec![vec![9, 8, 7], vec![6, 5, 4], vec![3, 2, 1]]);
+
+        assert_eq!(a.clone().strassen_mul(b.clone()), a * b);
+    }
+
+    #[test]
+    fn test_mul_dispatches_to_strassen_above_threshold() {
+        let n = 65;
+        let a: Matrix<i32> = Matrix::diagonal_from_constant((n, n), 1);
+        let b: Matrix<i32> = Matrix::from_constant((n, n), 2);
+
+        assert_eq!(a * b, Matrix::from_constant((n, n), 2));
+    }
+
+    #[test]
+    fn test_non_numeric_element_type_can_be_constructed_and_transposed() {
+        let m: Matrix<String> = Matrix::from_data(vec![
+            vec!["a".to_string(), "b".to_string()],
+            vec!["c".to_string(), "d".to_string()],
+        ]);
+
+        let transposed: Matrix<String> = m.transpose();
+
+        assert_eq!(
+            transposed,
+            Matrix::from_data(vec![
+                vec!["a".to_string(), "c".to_string()],
+                vec!["b".to_string(), "d".to_string()],
+            ])
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_roundtrip() {
+        let m: Matrix<i32> = Matrix::from_data(vec![vec![1, 2], vec![3, 4]]);
+
+        let json: String = serde_json::to_string(&m).unwrap();
+        let deserialized: Matrix<i32> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(m, deserialized);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_rejects_ragged_rows() {
+        let result: Result<Matrix<i32>, _> = serde_json::from_str("[[1, 2], [3]]");
+
+        assert!(result.is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_roundtrip_of_empty_matrices() {
+        let zero_by_zero: Matrix<i32> = Matrix::default_from_dimension((0, 0));
+        let json: String = serde_json::to_string(&zero_by_zero).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Matrix<i32>>(&json).unwrap(),
+            zero_by_zero
+        );
+
+        let three_by_zero: Matrix<i32> = Matrix::default_from_dimension((3, 0));
+        let json: String = serde_json::to_string(&three_by_zero).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Matrix<i32>>(&json).unwrap(),
+            three_by_zero
+        );
+    }
 }